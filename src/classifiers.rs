@@ -0,0 +1,134 @@
+use pyo3::prelude::*;  // Import PyO3's prelude for working with Python.
+
+use crate::validate_classifier_input;  // Shared validation for the non-Jenks classifiers below.
+
+/// Head/tail breaks (Jiang, 2013): recursively splits `data` at its mean,
+/// keeping the "head" (values above the mean) and continuing to recurse on
+/// the head as long as it remains a small fraction (`< head_fraction`) of the
+/// subset it came from. The accumulated means become the break points, so
+/// the resulting class count is driven by the data's tail shape rather than
+/// fixed in advance. Suited to heavy-tailed distributions where Jenks tends
+/// to do poorly.
+#[pyfunction]
+pub fn head_tail_breaks(data: Vec<f64>, head_fraction: f64) -> PyResult<Vec<f64>> {
+    if data.is_empty() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Data must not be empty.",
+        ));
+    }
+
+    let mut breaks = Vec::new();
+    let mut subset = data;
+
+    loop {
+        let n = subset.len();
+        if n < 2 {
+            break;
+        }
+
+        let mean = subset.iter().sum::<f64>() / n as f64;
+        breaks.push(mean);
+
+        let head: Vec<f64> = subset.into_iter().filter(|&x| x > mean).collect();
+        let head_is_minority = (head.len() as f64 / n as f64) < head_fraction;
+        if head.len() < 2 || !head_is_minority {
+            break;
+        }
+        subset = head;
+    }
+
+    Ok(breaks)
+}
+
+/// Equal-interval classifier: splits sorted `data` into `num_classes` classes
+/// of equal width between the data min and max.
+#[pyfunction]
+pub fn equal_interval_breaks(data: Vec<f64>, num_classes: usize) -> PyResult<Vec<f64>> {
+    validate_classifier_input(&data, num_classes)?;
+
+    let min = data[0];
+    let max = *data.last().unwrap();
+    let step = (max - min) / num_classes as f64;
+
+    Ok((0..=num_classes).map(|i| min + step * i as f64).collect())
+}
+
+/// Quantile classifier: splits sorted `data` into `num_classes` classes each
+/// holding (as close as linear interpolation allows) the same number of
+/// points.
+#[pyfunction]
+pub fn quantile_breaks(data: Vec<f64>, num_classes: usize) -> PyResult<Vec<f64>> {
+    validate_classifier_input(&data, num_classes)?;
+
+    let last = (data.len() - 1) as f64;
+    let breaks = (0..=num_classes)
+        .map(|i| {
+            let pos = i as f64 * last / num_classes as f64;
+            let lower = pos.floor() as usize;
+            let upper = pos.ceil() as usize;
+            let frac = pos - lower as f64;
+            data[lower] + frac * (data[upper] - data[lower])
+        })
+        .collect();
+
+    Ok(breaks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_tail_breaks_recurses_while_head_stays_minority() {
+        // A heavy tail of small values plus a handful of large outliers: the
+        // head (above the mean) should stay a small fraction of its subset
+        // for more than one split, so more than one break is produced.
+        let data = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 50.0, 100.0];
+        let breaks = head_tail_breaks(data, 0.4).unwrap();
+
+        assert!(breaks.len() >= 2);
+        assert!(breaks.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn head_tail_breaks_rejects_empty_data() {
+        assert!(head_tail_breaks(vec![], 0.4).is_err());
+    }
+
+    #[test]
+    fn equal_interval_breaks_splits_domain_into_equal_width_classes() {
+        let data = vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0];
+        let breaks = equal_interval_breaks(data, 5).unwrap();
+
+        assert_eq!(breaks, vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+    }
+
+    #[test]
+    fn equal_interval_breaks_allows_more_classes_than_data_points() {
+        // Only 2 points but 4 classes requested: unlike the Jenks DP, this
+        // scheme has no one-point-per-class constraint, so it must succeed.
+        let data = vec![0.0, 10.0];
+        let breaks = equal_interval_breaks(data, 4).unwrap();
+
+        assert_eq!(breaks, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+    }
+
+    #[test]
+    fn quantile_breaks_splits_data_into_equal_count_classes() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let breaks = quantile_breaks(data, 4).unwrap();
+
+        assert_eq!(breaks.len(), 5);
+        assert_eq!(breaks.first().copied(), Some(1.0));
+        assert_eq!(breaks.last().copied(), Some(9.0));
+        assert!(breaks.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn quantile_breaks_allows_more_classes_than_data_points() {
+        let data = vec![0.0, 10.0];
+        let breaks = quantile_breaks(data, 4).unwrap();
+
+        assert_eq!(breaks, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+    }
+}
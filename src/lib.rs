@@ -1,58 +1,123 @@
+use numpy::PyReadonlyArray1;  // Zero-copy view into a caller's NumPy array.
 use pyo3::prelude::*;  // Import PyO3's prelude for working with Python.
 use pyo3::wrap_pyfunction;  // Import a macro to expose Rust functions to Python.
+use rayon::prelude::*;  // Parallel iterators for the multi-threaded DP row computation.
+use std::collections::BTreeMap;  // Ordered map for the per-class-count results of `jenks_breaks_range`.
 
-/// This function calculates Jenks natural breaks optimization using dynamic programming.
-#[pyfunction]  // Exposes the function to Python.
-fn jenks_breaks_optimized(data: Vec<f64>, num_classes: usize) -> PyResult<Vec<usize>> {
-    let n_data = data.len();  // Get the length of the input data.
+mod classifiers;  // Alternative univariate classification schemes (head/tail, quantile, equal-interval).
+use classifiers::{equal_interval_breaks, head_tail_breaks, quantile_breaks};
 
-    // Error handling for invalid input.
-    if num_classes == 0 {
-        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            "Number of classes must be a positive integer.",
-        ));
+/// Holds the lower-class-limits table produced by `build_jenks_dp` (the
+/// variance table is only needed during the build and is not kept), plus the
+/// cumulative sums needed to recover the sum-of-squared-deviations of any class.
+/// Each input element carries a weight (1.0 for a plain, non-deduplicated
+/// input), so a unique value standing in for several repeated points is
+/// handled the same way as that many individual points.
+struct JenksDp {
+    n_data: usize,
+    max_classes: usize,
+    lower_class_limits: Vec<usize>,
+    cumulative_sum: Vec<f64>,
+    cumulative_sum_squares: Vec<f64>,
+    cumulative_weight: Vec<f64>,
+}
+
+impl JenksDp {
+    /// Index helper for the flattened `(n_data + 1) * (max_classes + 1)` table.
+    fn idx(&self, i: usize, j: usize) -> usize {
+        i * (self.max_classes + 1) + j
     }
-    if num_classes > n_data {
-        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            "Number of classes cannot exceed number of data points.",
-        ));
+
+    /// Backtracks the DP table to recover the break indices for a given class
+    /// count `num_classes` (which must be `<= max_classes`). The DP table for
+    /// `max_classes` already contains optimal lower-class limits for every
+    /// smaller class count, so this is cheap and requires no re-computation.
+    fn break_indices(&self, num_classes: usize) -> Vec<usize> {
+        let mut break_indices = vec![0; num_classes - 1];  // Holds the final breakpoints.
+        let mut k = self.n_data;  // Start backtracking from the last data point.
+        for j in (2..=num_classes).rev() {
+            break_indices[j - 2] = self.lower_class_limits[self.idx(k, j)] - 1;
+            k = break_indices[j - 2];
+        }
+        break_indices
     }
 
-    // Check if the data is sorted; if not, return an error.
-    if !data.windows(2).all(|w| w[0] <= w[1]) {
-        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            "The input NumPy array must be sorted.",
-        ));
+    /// Sum of squared deviations of `data[start..end]` (0-indexed, end-exclusive)
+    /// from that slice's own mean, computed from the cumulative sum tables.
+    fn class_variance(&self, start: usize, end: usize) -> f64 {
+        let sum = self.cumulative_sum[end] - self.cumulative_sum[start];
+        let sum_squares = self.cumulative_sum_squares[end] - self.cumulative_sum_squares[start];
+        let w = self.cumulative_weight[end] - self.cumulative_weight[start];
+        sum_squares - (sum * sum) / w
     }
 
-    // Precompute cumulative sums and cumulative sums of squares for variance calculation.
+    /// Sum of squared deviations of every class from its own mean (SDCM), for
+    /// the classification produced by `break_indices(num_classes)`.
+    fn sdcm(&self, num_classes: usize) -> f64 {
+        let breaks = self.break_indices(num_classes);
+        let mut bounds = Vec::with_capacity(num_classes + 1);
+        bounds.push(0);
+        bounds.extend_from_slice(&breaks);
+        bounds.push(self.n_data);
+
+        bounds
+            .windows(2)
+            .map(|w| self.class_variance(w[0], w[1]))
+            .sum()
+    }
+
+    /// Sum of squared deviations of all points from the global mean (SDAM).
+    fn sdam(&self) -> f64 {
+        self.class_variance(0, self.n_data)
+    }
+}
+
+/// Builds the Jenks natural breaks dynamic programming tables for sorted
+/// `data`, good for any class count up to `max_classes`. Every point counts
+/// with weight 1.
+fn build_jenks_dp(data: &[f64], max_classes: usize) -> JenksDp {
+    build_jenks_dp_weighted(data, &vec![1.0; data.len()], max_classes)
+}
+
+/// Builds the Jenks natural breaks dynamic programming tables for sorted,
+/// deduplicated `values`, where `weights[i]` is the number of original points
+/// that collapsed into `values[i]`. Passing all-ones weights is equivalent to
+/// `build_jenks_dp`.
+fn build_jenks_dp_weighted(values: &[f64], weights: &[f64], max_classes: usize) -> JenksDp {
+    let n_data = values.len();  // Get the length of the (deduplicated) input.
+
+    // Precompute cumulative sums, sums of squares, and weight for variance calculation.
     let mut cumulative_sum = vec![0.0; n_data + 1];  // Cumulative sum of data.
     let mut cumulative_sum_squares = vec![0.0; n_data + 1];  // Cumulative sum of squares of data.
+    let mut cumulative_weight = vec![0.0; n_data + 1];  // Cumulative weight (point count).
 
-    // Fill the cumulative sum and sum of squares.
+    // Fill the cumulative sum, sum of squares, and weight.
     for i in 1..=n_data {
-        cumulative_sum[i] = cumulative_sum[i - 1] + data[i - 1];
-        cumulative_sum_squares[i] = cumulative_sum_squares[i - 1] + data[i - 1] * data[i - 1];
+        cumulative_sum[i] = cumulative_sum[i - 1] + weights[i - 1] * values[i - 1];
+        cumulative_sum_squares[i] =
+            cumulative_sum_squares[i - 1] + weights[i - 1] * values[i - 1] * values[i - 1];
+        cumulative_weight[i] = cumulative_weight[i - 1] + weights[i - 1];
     }
 
     // Initialize tables for class limits and variance combinations.
-    let mut lower_class_limits = vec![0; (n_data + 1) * (num_classes + 1)];
-    let mut variance_combinations = vec![f64::INFINITY; (n_data + 1) * (num_classes + 1)];
+    let mut lower_class_limits = vec![0; (n_data + 1) * (max_classes + 1)];
+    let mut variance_combinations = vec![f64::INFINITY; (n_data + 1) * (max_classes + 1)];
 
     // Helper closure to calculate the 1D index for our 2D DP tables.
-    let idx = |i, j| i * (num_classes + 1) + j;
+    let idx = |i, j| i * (max_classes + 1) + j;
 
     // Initialize the first class (j = 1) for all data points.
     for i in 1..=n_data {
         lower_class_limits[idx(i, 1)] = 1;  // Start of the first class.
         let sum = cumulative_sum[i] - cumulative_sum[0];
         let sum_squares = cumulative_sum_squares[i] - cumulative_sum_squares[0];
-        let variance = sum_squares - (sum * sum) / (i as f64);  // Calculate variance.
+        let w = cumulative_weight[i] - cumulative_weight[0];
+        let variance = sum_squares - (sum * sum) / w;  // Calculate variance.
         variance_combinations[idx(i, 1)] = variance;
     }
 
     // Dynamic Programming: Compute the best class breakpoints for classes > 1.
-    for j in 2..=num_classes {
+    for j in 2..=max_classes {
         for i in j..=n_data {
             let mut min_variance = f64::INFINITY;
             let mut min_k = 0;
@@ -61,7 +126,7 @@ fn jenks_breaks_optimized(data: Vec<f64>, num_classes: usize) -> PyResult<Vec<us
             for k in (j - 1)..i {
                 let sum = cumulative_sum[i] - cumulative_sum[k];
                 let sum_squares = cumulative_sum_squares[i] - cumulative_sum_squares[k];
-                let w = (i - k) as f64;
+                let w = cumulative_weight[i] - cumulative_weight[k];
                 let variance = sum_squares - (sum * sum) / w;
 
                 let total_variance = variance_combinations[idx(k, j - 1)] + variance;
@@ -79,15 +144,371 @@ fn jenks_breaks_optimized(data: Vec<f64>, num_classes: usize) -> PyResult<Vec<us
         }
     }
 
+    JenksDp {
+        n_data,
+        max_classes,
+        lower_class_limits,
+        cumulative_sum,
+        cumulative_sum_squares,
+        cumulative_weight,
+    }
+}
+
+/// Validates the common preconditions shared by the Jenks entry points: a
+/// positive class count, no more classes than data points, and sorted input.
+pub(crate) fn validate_jenks_input(data: &[f64], num_classes: usize) -> PyResult<()> {
+    if num_classes == 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Number of classes must be a positive integer.",
+        ));
+    }
+    if num_classes > data.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Number of classes cannot exceed number of data points.",
+        ));
+    }
+    if !data.windows(2).all(|w| w[0] <= w[1]) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "The input NumPy array must be sorted.",
+        ));
+    }
+    Ok(())
+}
+
+/// Validates the preconditions shared by the non-Jenks classifiers
+/// (`equal_interval_breaks`, `quantile_breaks`): a positive class count and
+/// sorted, non-empty input. Unlike `validate_jenks_input`, this does not cap
+/// `num_classes` at the number of data points, since these schemes don't rely
+/// on Jenks' one-point-per-class DP and can legitimately split a handful of
+/// points into many interpolated buckets.
+pub(crate) fn validate_classifier_input(data: &[f64], num_classes: usize) -> PyResult<()> {
+    if num_classes == 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Number of classes must be a positive integer.",
+        ));
+    }
+    if data.is_empty() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Data must not be empty.",
+        ));
+    }
+    if !data.windows(2).all(|w| w[0] <= w[1]) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "The input NumPy array must be sorted.",
+        ));
+    }
+    Ok(())
+}
+
+/// This function calculates Jenks natural breaks optimization using dynamic programming.
+#[pyfunction]  // Exposes the function to Python.
+fn jenks_breaks_optimized(data: Vec<f64>, num_classes: usize) -> PyResult<Vec<usize>> {
+    validate_jenks_input(&data, num_classes)?;
+    let dp = build_jenks_dp(&data, num_classes);
+    Ok(dp.break_indices(num_classes))  // Return the computed break indices.
+}
+
+/// Picks the smallest class count (up to `max_classes`) whose Goodness of
+/// Variance Fit reaches `min_gvf`, falling back to the best-scoring count if
+/// none clears the threshold. GVF is `(SDAM - SDCM) / SDAM`, where SDAM is the
+/// sum of squared deviations from the global mean and SDCM is the summed
+/// sum-of-squared-deviations of each class from its own mean. The DP table for
+/// `max_classes` already encodes optimal limits for every smaller class count,
+/// so only one DP pass is needed regardless of how many candidates are tried.
+#[pyfunction]
+fn jenks_best_breaks(
+    data: Vec<f64>,
+    max_classes: usize,
+    min_gvf: f64,
+) -> PyResult<(usize, Vec<usize>)> {
+    validate_jenks_input(&data, max_classes)?;
+    if max_classes < 2 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "max_classes must be at least 2.",
+        ));
+    }
+
+    let dp = build_jenks_dp(&data, max_classes);
+    let sdam = dp.sdam();
+
+    let mut best_num_classes = 2;
+    let mut best_gvf = f64::NEG_INFINITY;
+
+    for num_classes in 2..=max_classes {
+        let sdcm = dp.sdcm(num_classes);
+        let gvf = if sdam > 0.0 { (sdam - sdcm) / sdam } else { 1.0 };
+
+        if gvf > best_gvf {
+            best_gvf = gvf;
+            best_num_classes = num_classes;
+        }
+
+        if gvf >= min_gvf {
+            return Ok((num_classes, dp.break_indices(num_classes)));
+        }
+    }
+
+    // No candidate reached the threshold; return the best-scoring one.
+    Ok((best_num_classes, dp.break_indices(best_num_classes)))
+}
+
+/// Collapses already-sorted `sorted` into its unique values, pairing each one
+/// with how many times it occurred (as an `f64` weight, ready for
+/// `build_jenks_dp_weighted`).
+fn dedup_sorted(sorted: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut values: Vec<f64> = Vec::new();
+    let mut counts: Vec<f64> = Vec::new();
+
+    for &v in sorted {
+        if let Some(&last) = values.last() {
+            if last == v {
+                *counts.last_mut().unwrap() += 1.0;
+                continue;
+            }
+        }
+        values.push(v);
+        counts.push(1.0);
+    }
+
+    (values, counts)
+}
+
+/// Variant of `jenks_breaks_optimized` that accepts unsorted, duplicate-heavy
+/// input directly. The data is sorted internally and collapsed to its unique
+/// values (remembering each value's multiplicity), the DP runs on that
+/// smaller weighted sequence, and the resulting break indices are mapped back
+/// into the original, full-length sorted domain.
+///
+/// The deduplicated, weighted DP can only ever produce as many classes as
+/// there are unique values (it has no way to split a single weighted point
+/// into several, unlike `jenks_breaks_optimized` working on raw indices,
+/// which can place a break in the middle of a run of equal values at zero
+/// variance cost). So when `num_classes` is at least the number of unique
+/// values, the achieved class count is capped at the unique count and its
+/// boundaries are returned directly instead of running the DP. The achieved
+/// count is returned alongside the breaks — like `jenks_best_breaks` — so
+/// callers can tell when it fell short of the requested `num_classes`.
+#[pyfunction]
+fn jenks_breaks_unsorted(data: Vec<f64>, num_classes: usize) -> PyResult<(usize, Vec<usize>)> {
+    let n_data = data.len();  // Get the length of the input data.
+
+    // Error handling for invalid input.
+    if num_classes == 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Number of classes must be a positive integer.",
+        ));
+    }
+    if num_classes > n_data {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Number of classes cannot exceed number of data points.",
+        ));
+    }
+    if data.iter().any(|v| v.is_nan()) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "The input data must not contain NaN values.",
+        ));
+    }
+
+    // Sort a copy of the data; no ordering is assumed of the caller's input.
+    let mut sorted_data = data;
+    sorted_data.sort_by(|a, b| a.partial_cmp(b).expect("NaN values are rejected above"));
+
+    let (unique_values, counts) = dedup_sorted(&sorted_data);
+
+    if num_classes >= unique_values.len() {
+        // Sanity guard: with this few distinct values the optimizer can't do
+        // better than one class per unique value, so return those boundaries
+        // and the achieved (possibly smaller than requested) class count.
+        let mut cumulative = 0usize;
+        let mut breaks = Vec::with_capacity(unique_values.len().saturating_sub(1));
+        for count in counts.iter().take(unique_values.len().saturating_sub(1)) {
+            cumulative += *count as usize;
+            breaks.push(cumulative);
+        }
+        return Ok((unique_values.len(), breaks));
+    }
+
+    // Run the DP on the deduplicated, weighted sequence, then map the break
+    // positions (indices into `unique_values`) back to indices into the full,
+    // original-length sorted domain via the cumulative weight (point count).
+    let dp = build_jenks_dp_weighted(&unique_values, &counts, num_classes);
+    let breaks = dp
+        .break_indices(num_classes)
+        .into_iter()
+        .map(|p| dp.cumulative_weight[p] as usize)
+        .collect();
+    Ok((num_classes, breaks))
+}
+
+/// Computes break indices for every class count from 2 up to `max_classes` in
+/// a single DP pass, keyed by class count. Useful for interactive tools (e.g.
+/// a choropleth slider) that let a user pick the number of classes: the DP
+/// table built for `max_classes` already contains optimal lower-class limits
+/// for every smaller class count, so backtracking each one is cheap compared
+/// to calling `jenks_breaks_optimized` once per candidate class count.
+#[pyfunction]
+fn jenks_breaks_range(data: Vec<f64>, max_classes: usize) -> PyResult<BTreeMap<usize, Vec<usize>>> {
+    validate_jenks_input(&data, max_classes)?;
+    if max_classes < 2 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "max_classes must be at least 2.",
+        ));
+    }
+
+    let dp = build_jenks_dp(&data, max_classes);
+    Ok((2..=max_classes)
+        .map(|num_classes| (num_classes, dp.break_indices(num_classes)))
+        .collect())
+}
+
+/// Full result of classifying data into Jenks classes: the break values
+/// (including the data min/max as outer bounds), each class's `[lower,
+/// upper]` range, how many points fall in each class, each class's own
+/// sum-of-squared-deviations, and the overall Goodness of Variance Fit.
+/// Returned from `jenks_classify` so callers don't have to re-derive any of
+/// this from bare break indices.
+#[pyclass]
+struct JenksClassification {
+    #[pyo3(get)]
+    breaks: Vec<f64>,
+    #[pyo3(get)]
+    ranges: Vec<(f64, f64)>,
+    #[pyo3(get)]
+    counts: Vec<usize>,
+    #[pyo3(get)]
+    variances: Vec<f64>,
+    #[pyo3(get)]
+    gvf: f64,
+}
+
+/// Classifies sorted `data` into `num_classes` Jenks natural breaks classes
+/// and returns the full classification, not just break indices: break values
+/// (data min and max included as outer bounds), per-class `[lower, upper]`
+/// ranges, per-class point counts, per-class sum-of-squared-deviations, and
+/// the overall GVF.
+#[pyfunction]
+fn jenks_classify(data: Vec<f64>, num_classes: usize) -> PyResult<JenksClassification> {
+    validate_jenks_input(&data, num_classes)?;
+    let dp = build_jenks_dp(&data, num_classes);
+
+    // Class bounds in the index domain: 0, each break index, then n_data.
+    let mut bounds = Vec::with_capacity(num_classes + 1);
+    bounds.push(0);
+    bounds.extend(dp.break_indices(num_classes));
+    bounds.push(data.len());
+
+    let breaks: Vec<f64> = bounds.iter().map(|&i| data[i.min(data.len() - 1)]).collect();
+    let ranges: Vec<(f64, f64)> = breaks.windows(2).map(|w| (w[0], w[1])).collect();
+    let counts: Vec<usize> = bounds.windows(2).map(|w| w[1] - w[0]).collect();
+    let variances: Vec<f64> = bounds
+        .windows(2)
+        .map(|w| dp.class_variance(w[0], w[1]))
+        .collect();
+
+    let sdam = dp.sdam();
+    let sdcm: f64 = variances.iter().sum();
+    let gvf = if sdam > 0.0 { (sdam - sdcm) / sdam } else { 1.0 };
+
+    Ok(JenksClassification {
+        breaks,
+        ranges,
+        counts,
+        variances,
+        gvf,
+    })
+}
+
+/// NumPy-zero-copy, multi-threaded variant of `jenks_breaks_optimized`, meant
+/// for arrays too large to comfortably duplicate into a `Vec<f64>` or to run
+/// single-threaded. Reads straight from the caller's NumPy buffer via
+/// `PyReadonlyArray1` instead of copying it, then delegates to
+/// `parallel_jenks_breaks`.
+#[pyfunction]
+fn jenks_breaks_numpy(data: PyReadonlyArray1<f64>, num_classes: usize) -> PyResult<Vec<usize>> {
+    let data = data.as_slice()?;  // Zero-copy view into the caller's NumPy array.
+    validate_jenks_input(data, num_classes)?;
+    Ok(parallel_jenks_breaks(data, num_classes))
+}
+
+/// Computes Jenks break indices for sorted `data`, parallelizing the DP's row
+/// computation with rayon: for a fixed class count `j`, every cell `i` of row
+/// `j` only depends on row `j - 1`, which is already finalized, so every `i` in a
+/// row can be computed concurrently. The variance table is also kept as only
+/// two rolling rows (previous and current) rather than the full
+/// `(n_data + 1) * (num_classes + 1)` table, since this only needs the final
+/// class count's breaks rather than breaks for every smaller class count.
+/// `lower_class_limits` is still kept in full, as backtracking needs every
+/// row. Results are identical to `jenks_breaks_optimized`. Assumes `data` has
+/// already passed `validate_jenks_input`.
+fn parallel_jenks_breaks(data: &[f64], num_classes: usize) -> Vec<usize> {
+    let n_data = data.len();
+
+    // Precompute cumulative sums and cumulative sums of squares for variance calculation.
+    let mut cumulative_sum = vec![0.0; n_data + 1];
+    let mut cumulative_sum_squares = vec![0.0; n_data + 1];
+    for i in 1..=n_data {
+        cumulative_sum[i] = cumulative_sum[i - 1] + data[i - 1];
+        cumulative_sum_squares[i] = cumulative_sum_squares[i - 1] + data[i - 1] * data[i - 1];
+    }
+
+    // Lower class limits still need the full table for backtracking, but the
+    // variance table only ever needs the previous and current rows.
+    let mut lower_class_limits = vec![0usize; (n_data + 1) * (num_classes + 1)];
+    let idx = |i, j| i * (num_classes + 1) + j;
+
+    // Initialize the first class (j = 1) for all data points.
+    let mut prev_row = vec![f64::INFINITY; n_data + 1];
+    for i in 1..=n_data {
+        lower_class_limits[idx(i, 1)] = 1;  // Start of the first class.
+        let sum = cumulative_sum[i];
+        let sum_squares = cumulative_sum_squares[i];
+        prev_row[i] = sum_squares - (sum * sum) / (i as f64);
+    }
+
+    // Dynamic Programming: Compute the best class breakpoints for classes > 1,
+    // one row at a time, with every cell of a row computed in parallel.
+    for j in 2..=num_classes {
+        let row: Vec<(usize, f64)> = (j..=n_data)
+            .into_par_iter()
+            .map(|i| {
+                let mut min_variance = f64::INFINITY;
+                let mut min_k = 0;
+
+                for k in (j - 1)..i {
+                    let sum = cumulative_sum[i] - cumulative_sum[k];
+                    let sum_squares = cumulative_sum_squares[i] - cumulative_sum_squares[k];
+                    let w = (i - k) as f64;
+                    let variance = sum_squares - (sum * sum) / w;
+
+                    let total_variance = prev_row[k] + variance;
+                    if total_variance < min_variance {
+                        min_variance = total_variance;
+                        min_k = k + 1;
+                    }
+                }
+
+                (min_k, min_variance)
+            })
+            .collect();
+
+        let mut current_row = vec![f64::INFINITY; n_data + 1];
+        for (offset, (min_k, min_variance)) in row.into_iter().enumerate() {
+            let i = j + offset;
+            lower_class_limits[idx(i, j)] = min_k;
+            current_row[i] = min_variance;
+        }
+        prev_row = current_row;
+    }
+
     // Backtrack to find the break indices for each class.
-    let mut break_indices = vec![0; num_classes - 1];  // Holds the final breakpoints.
-    let mut k = n_data;  // Start backtracking from the last data point.
+    let mut break_indices = vec![0; num_classes - 1];
+    let mut k = n_data;
     for j in (2..=num_classes).rev() {
         break_indices[j - 2] = lower_class_limits[idx(k, j)] - 1;
         k = break_indices[j - 2];
     }
 
-    Ok(break_indices)  // Return the computed break indices.
+    break_indices
 }
 
 /// Define the Python module.
@@ -95,5 +516,175 @@ fn jenks_breaks_optimized(data: Vec<f64>, num_classes: usize) -> PyResult<Vec<us
 fn jenks_breaks(_py: Python, m: &PyModule) -> PyResult<()> {
     // Add the optimized Jenks Breaks function to the Python module.
     m.add_function(wrap_pyfunction!(jenks_breaks_optimized, m)?)?;
+    // Add the auto-selecting Jenks Breaks function to the Python module.
+    m.add_function(wrap_pyfunction!(jenks_best_breaks, m)?)?;
+    // Add the unsorted/duplicate-tolerant Jenks Breaks function to the Python module.
+    m.add_function(wrap_pyfunction!(jenks_breaks_unsorted, m)?)?;
+    // Add the full-classification Jenks Breaks function and its result type.
+    m.add_function(wrap_pyfunction!(jenks_classify, m)?)?;
+    m.add_class::<JenksClassification>()?;
+    // Add the single-pass, many-class-counts Jenks Breaks function to the Python module.
+    m.add_function(wrap_pyfunction!(jenks_breaks_range, m)?)?;
+    // Add the alternative univariate classification schemes to the Python module.
+    m.add_function(wrap_pyfunction!(head_tail_breaks, m)?)?;
+    m.add_function(wrap_pyfunction!(equal_interval_breaks, m)?)?;
+    m.add_function(wrap_pyfunction!(quantile_breaks, m)?)?;
+    // Add the zero-copy, multi-threaded Jenks Breaks function to the Python module.
+    m.add_function(wrap_pyfunction!(jenks_breaks_numpy, m)?)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut data: Vec<f64>) -> Vec<f64> {
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        data
+    }
+
+    #[test]
+    fn jenks_best_breaks_picks_smallest_k_meeting_threshold() {
+        // Three well-separated clusters: GVF should already be very high with
+        // 3 classes, well before the requested max of 4, so 3 is what's picked.
+        let data = vec![1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 50.0, 51.0, 52.0];
+        let (achieved, _) = jenks_best_breaks(data, 4, 0.99).unwrap();
+        assert_eq!(achieved, 3);
+    }
+
+    #[test]
+    fn jenks_best_breaks_falls_back_to_best_scoring_when_threshold_unreachable() {
+        // GVF never exceeds 1.0, so a threshold above 1.0 can never be met and
+        // this must fall back to the best-scoring candidate tried, which is
+        // the largest class count (GVF only improves as classes are added).
+        let data = vec![1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 50.0, 51.0, 52.0];
+        let (achieved, breaks) = jenks_best_breaks(data.clone(), 4, 1.5).unwrap();
+        assert_eq!(achieved, 4);
+        assert_eq!(breaks, jenks_breaks_optimized(data, 4).unwrap());
+    }
+
+    #[test]
+    fn jenks_best_breaks_matches_optimized_breaks_for_achieved_k() {
+        let data = vec![1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 50.0, 51.0, 52.0];
+        let (achieved, breaks) = jenks_best_breaks(data.clone(), 4, 0.95).unwrap();
+        assert_eq!(breaks, jenks_breaks_optimized(data, achieved).unwrap());
+    }
+
+    #[test]
+    fn jenks_classify_reports_consistent_breaks_ranges_counts_and_gvf() {
+        // Three well-separated clusters, so the optimal 3-class partition is
+        // exactly each cluster as its own class.
+        let data = vec![1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 50.0, 51.0, 52.0];
+        let result = jenks_classify(data.clone(), 3).unwrap();
+
+        assert_eq!(result.counts, vec![3, 3, 3]);
+        assert_eq!(result.counts.iter().sum::<usize>(), data.len());
+        assert_eq!(result.breaks.len(), 4);
+        assert_eq!(result.ranges.len(), 3);
+        assert_eq!(result.breaks.first().copied(), data.first().copied());
+        assert_eq!(result.breaks.last().copied(), data.last().copied());
+
+        for (range, pair) in result.ranges.iter().zip(result.breaks.windows(2)) {
+            assert_eq!(*range, (pair[0], pair[1]));
+        }
+
+        // Independently derive SDAM/SDCM/GVF from the known cluster split,
+        // rather than reusing the production code path being tested.
+        let mean = |s: &[f64]| s.iter().sum::<f64>() / s.len() as f64;
+        let ssd = |s: &[f64]| {
+            let m = mean(s);
+            s.iter().map(|v| (v - m) * (v - m)).sum::<f64>()
+        };
+        let global_mean = mean(&data);
+        let sdam: f64 = data
+            .iter()
+            .map(|v| (v - global_mean) * (v - global_mean))
+            .sum();
+        let sdcm = ssd(&data[0..3]) + ssd(&data[3..6]) + ssd(&data[6..9]);
+        let expected_gvf = (sdam - sdcm) / sdam;
+
+        assert!((result.gvf - expected_gvf).abs() < 1e-9);
+    }
+
+    #[test]
+    fn jenks_breaks_unsorted_matches_optimized_on_shuffled_input() {
+        let sorted_data = vec![1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 50.0, 51.0, 52.0];
+        let shuffled = vec![51.0, 2.0, 12.0, 1.0, 50.0, 11.0, 3.0, 52.0, 10.0];
+
+        let expected = jenks_breaks_optimized(sorted_data, 3).unwrap();
+        let (achieved, actual) = jenks_breaks_unsorted(shuffled, 3).unwrap();
+
+        assert_eq!(achieved, 3);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn jenks_breaks_unsorted_remaps_duplicate_heavy_breaks_to_full_domain() {
+        // 3 copies of 1.0, 2 copies of 2.0, 4 copies of 5.0: the unique/weighted
+        // DP must remap its break back into this 9-point domain, not the
+        // 3-point unique-value domain.
+        let data = vec![1.0, 1.0, 1.0, 2.0, 2.0, 5.0, 5.0, 5.0, 5.0];
+        let expected = jenks_breaks_optimized(sorted(data.clone()), 2).unwrap();
+        let (achieved, actual) = jenks_breaks_unsorted(data, 2).unwrap();
+
+        assert_eq!(achieved, 2);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn jenks_breaks_unsorted_returns_unique_boundaries_when_classes_equal_unique_values() {
+        let data = vec![3.0, 1.0, 1.0, 2.0, 2.0, 2.0];
+        // Only 3 unique values; asking for 3 classes hits the sanity guard and
+        // returns the unique-value boundaries directly: 2 copies of 1.0, then
+        // 3 copies of 2.0. The achieved count matches what was requested.
+        let (achieved, breaks) = jenks_breaks_unsorted(data, 3).unwrap();
+        assert_eq!(achieved, 3);
+        assert_eq!(breaks, vec![2, 5]);
+    }
+
+    #[test]
+    fn jenks_breaks_unsorted_reports_achieved_count_below_requested() {
+        let data = vec![3.0, 1.0, 1.0, 2.0, 2.0, 2.0];
+        // Only 3 unique values; the deduplicated DP can't produce more than 3
+        // classes, so requesting 5 must honestly report an achieved count of
+        // 3 rather than silently returning fewer breaks than `num_classes`
+        // implies (unlike `jenks_breaks_optimized`, which can split the
+        // duplicate runs to reach 5).
+        let (achieved, breaks) = jenks_breaks_unsorted(data, 5).unwrap();
+        assert_eq!(achieved, 3);
+        assert_eq!(breaks, vec![2, 5]);
+    }
+
+    #[test]
+    fn jenks_breaks_unsorted_rejects_nan() {
+        let data = vec![1.0, f64::NAN, 3.0];
+        assert!(jenks_breaks_unsorted(data, 2).is_err());
+    }
+
+    #[test]
+    fn jenks_breaks_range_matches_optimized_for_every_k() {
+        let data = vec![
+            1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 50.0, 51.0, 52.0, 90.0, 91.0, 92.0,
+        ];
+        let max_classes = 5;
+        let range = jenks_breaks_range(data.clone(), max_classes).unwrap();
+
+        assert_eq!(range.len(), max_classes - 1);
+        for num_classes in 2..=max_classes {
+            let expected = jenks_breaks_optimized(data.clone(), num_classes).unwrap();
+            assert_eq!(range[&num_classes], expected);
+        }
+    }
+
+    #[test]
+    fn parallel_jenks_breaks_matches_serial_dp() {
+        let data = vec![
+            1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 13.0, 50.0, 51.0, 52.0, 53.0, 90.0, 91.0, 92.0,
+        ];
+
+        let serial = jenks_breaks_optimized(data.clone(), 4).unwrap();
+        let parallel = parallel_jenks_breaks(&data, 4);
+
+        assert_eq!(serial, parallel);
+    }
+}